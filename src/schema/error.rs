@@ -0,0 +1,84 @@
+use std::error::Error;
+use std::fmt;
+
+use combine::easy::Errors;
+
+use position::Pos;
+use tokenizer::Token;
+
+/// A single problem encountered while parsing.
+///
+/// The source position is tracked once on the enclosing
+/// [`SchemaParseError`] rather than per message. This is a deliberate
+/// choice, not an oversight: combine's `easy::Errors` only ever reports one
+/// stream position for a whole batch of accumulated problems, so a per-error
+/// `Pos` field here would just repeat that same value on every entry and
+/// imply a precision the parser doesn't actually have.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Error parsing schema
+///
+/// The parser accumulates *every* problem it can (e.g. several duplicate
+/// operation complaints) before bailing out, so this carries a list rather
+/// than a single error. Use [`errors`](SchemaParseError::errors) to report
+/// them all in one pass, and [`position`](SchemaParseError::position) for the
+/// source location they were reported at.
+#[derive(Debug, PartialEq)]
+pub struct SchemaParseError {
+    position: Pos,
+    errors: Vec<ParseError>,
+}
+
+impl SchemaParseError {
+    /// The source position the parse failed at. combine reports the whole
+    /// batch of accumulated problems at this single location.
+    pub fn position(&self) -> Pos {
+        self.position
+    }
+
+    /// Every individual problem collected during the failed parse, in the
+    /// order the parser reported them.
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+}
+
+impl<'a> From<Errors<Token<'a>, Token<'a>, Pos>> for SchemaParseError {
+    fn from(errors: Errors<Token<'a>, Token<'a>, Pos>) -> SchemaParseError {
+        SchemaParseError {
+            position: errors.position,
+            errors: errors.errors.iter()
+                .map(|e| ParseError {
+                    message: e.to_string(),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (idx, err) in self.errors.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", self.position, err)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for SchemaParseError {
+    fn description(&self) -> &str {
+        "error parsing graphql schema"
+    }
+}
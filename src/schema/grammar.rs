@@ -6,11 +6,67 @@ use combine::combinator::{sep_by1};
 
 use tokenizer::{Kind as T, Token, TokenStream};
 use helpers::{punct, ident, kind, name};
-use common::{directives, string};
+use common::{directives, string, default_value, parse_type};
+use position::Pos;
 use schema::error::{SchemaParseError};
 use schema::ast::*;
 
 
+/// Fold the `operation: Type` pairs found in the body of a `schema`
+/// definition or extension into the three root-operation slots.
+///
+/// Both `schema` and `schema_extension` accept the same body, so the
+/// duplicate-operation and unexpected-token diagnostics live here to avoid
+/// the two grammar functions drifting apart. Every problem is accumulated
+/// into the returned `Errors` rather than bailing on the first one.
+fn schema_operations<'a>(position: Pos, operations: Vec<(Token<'a>, NamedType)>)
+    -> Result<(Option<NamedType>, Option<NamedType>, Option<NamedType>),
+              Errors<Token<'a>, Token<'a>, Pos>>
+{
+    let mut query = None;
+    let mut mutation = None;
+    let mut subscription = None;
+    let mut err = Errors::empty(position);
+    for (oper, type_name) in operations {
+        match oper.value {
+            "query" if query.is_some() => {
+                err.add_error(Error::unexpected_static_message(
+                    "duplicate `query` operation"));
+            }
+            "query" => {
+                query = Some(type_name);
+            }
+            "mutation" if mutation.is_some() => {
+                err.add_error(Error::unexpected_static_message(
+                    "duplicate `mutation` operation"));
+            }
+            "mutation" => {
+                mutation = Some(type_name);
+            }
+            "subscription" if subscription.is_some() => {
+                err.add_error(Error::unexpected_static_message(
+                    "duplicate `subscription` operation"));
+            }
+            "subscription" => {
+                subscription = Some(type_name);
+            }
+            _ => {
+                err.add_error(Error::unexpected_token(oper));
+                err.add_error(
+                    Error::expected_static_message("query"));
+                err.add_error(
+                    Error::expected_static_message("mutation"));
+                err.add_error(
+                    Error::expected_static_message("subscription"));
+            }
+        }
+    }
+    if !err.errors.is_empty() {
+        return Err(err);
+    }
+    Ok((query, mutation, subscription))
+}
+
 pub fn schema<'a>(input: &mut TokenStream<'a>)
     -> ParseResult<SchemaDefinition, TokenStream<'a>>
 {
@@ -25,49 +81,11 @@ pub fn schema<'a>(input: &mut TokenStream<'a>)
             .skip(punct("}")),
     )
     .flat_map(|(position, directives, operations): (_, _, Vec<(Token, _)>)| {
-        let mut query = None;
-        let mut mutation = None;
-        let mut subscription = None;
-        let mut err = Errors::empty(position);
-        for (oper, type_name) in operations {
-            match oper.value {
-                "query" if query.is_some() => {
-                    err.add_error(Error::unexpected_static_message(
-                        "duplicate `query` operation"));
-                }
-                "query" => {
-                    query = Some(type_name);
-                }
-                "mutation" if mutation.is_some() => {
-                    err.add_error(Error::unexpected_static_message(
-                        "duplicate `mutation` operation"));
-                }
-                "mutation" => {
-                    mutation = Some(type_name);
-                }
-                "subscription" if subscription.is_some() => {
-                    err.add_error(Error::unexpected_static_message(
-                        "duplicate `subscription` operation"));
-                }
-                "subscription" => {
-                    subscription = Some(type_name);
-                }
-                _ => {
-                    err.add_error(Error::unexpected_token(oper));
-                    err.add_error(
-                        Error::expected_static_message("query"));
-                    err.add_error(
-                        Error::expected_static_message("mutation"));
-                    err.add_error(
-                        Error::expected_static_message("subscription"));
-                }
-            }
-        }
-        if !err.errors.is_empty() {
-            return Err(err);
-        }
+        let (query, mutation, subscription) =
+            schema_operations(position, operations)?;
         Ok(SchemaDefinition {
-            position, directives, query, mutation, subscription,
+            position, description: None, directives,
+            query, mutation, subscription,
         })
     })
     .parse_stream(input)
@@ -99,6 +117,73 @@ pub fn implements_interfaces<'a>(input: &mut TokenStream<'a>)
         .parse_stream(input)
 }
 
+pub fn input_value<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<InputValue, TokenStream<'a>>
+{
+    (
+        position(),
+        optional(parser(string)),
+        name(),
+        punct(":").with(parser(parse_type)),
+        optional(punct("=").with(parser(default_value))),
+        parser(directives),
+    )
+        .map(|(position, description, name, value_type, default_value,
+                directives)|
+        {
+            InputValue {
+                position, description, name, value_type, default_value,
+                directives,
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn arguments_definition<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<Vec<InputValue>, TokenStream<'a>>
+{
+    optional(
+        punct("(")
+        .with(many1(parser(input_value)))
+        .skip(punct(")"))
+    )
+        .map(|opt| opt.unwrap_or_else(Vec::new))
+        .parse_stream(input)
+}
+
+pub fn field<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<Field, TokenStream<'a>>
+{
+    (
+        position(),
+        optional(parser(string)),
+        name(),
+        parser(arguments_definition),
+        punct(":").with(parser(parse_type)),
+        parser(directives),
+    )
+        .map(|(position, description, name, arguments, field_type,
+                directives)|
+        {
+            Field {
+                position, description, name, arguments, field_type, directives,
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn fields<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<Vec<Field>, TokenStream<'a>>
+{
+    optional(
+        punct("{")
+        .with(many1(parser(field)))
+        .skip(punct("}"))
+    )
+        .map(|opt| opt.unwrap_or_else(Vec::new))
+        .parse_stream(input)
+}
+
 pub fn object_type<'a>(input: &mut TokenStream<'a>)
     -> ParseResult<ObjectType, TokenStream<'a>>
 {
@@ -107,12 +192,125 @@ pub fn object_type<'a>(input: &mut TokenStream<'a>)
         ident("type").with(name()),
         parser(implements_interfaces),
         parser(directives),
+        parser(fields),
     )
-        .map(|(position, name, interfaces, directives)| {
+        .map(|(position, name, interfaces, directives, fields)| {
             ObjectType {
                 position, description: None, name, directives,
                 implements_interfaces: interfaces,
-                fields: Vec::new(),  // TODO(tailhook)
+                fields,
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn interface_type<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<InterfaceType, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("interface").with(name()),
+        parser(implements_interfaces),
+        parser(directives),
+        parser(fields),
+    )
+        .map(|(position, name, interfaces, directives, fields)| {
+            InterfaceType {
+                position, description: None, name, directives,
+                implements_interfaces: interfaces,
+                fields,
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn union_members<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<Vec<NamedType>, TokenStream<'a>>
+{
+    optional(punct("|"))
+        .with(sep_by1(name(), punct("|")))
+        .parse_stream(input)
+}
+
+pub fn union_type<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<UnionType, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("union").with(name()),
+        parser(directives),
+        optional(punct("=").with(parser(union_members))),
+    )
+        .map(|(position, name, directives, types)| {
+            UnionType {
+                position, description: None, name, directives,
+                types: types.unwrap_or_else(Vec::new),
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn enum_value<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<EnumValue, TokenStream<'a>>
+{
+    (
+        position(),
+        optional(parser(string)),
+        name(),
+        parser(directives),
+    )
+        .map(|(position, description, name, directives)| {
+            EnumValue { position, description, name, directives }
+        })
+        .parse_stream(input)
+}
+
+pub fn enum_type<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<EnumType, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("enum").with(name()),
+        parser(directives),
+        optional(
+            punct("{")
+            .with(many1(parser(enum_value)))
+            .skip(punct("}"))
+        ),
+    )
+        .map(|(position, name, directives, values)| {
+            EnumType {
+                position, description: None, name, directives,
+                values: values.unwrap_or_else(Vec::new),
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn input_fields<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<Vec<InputValue>, TokenStream<'a>>
+{
+    optional(
+        punct("{")
+        .with(many1(parser(input_value)))
+        .skip(punct("}"))
+    )
+        .map(|opt| opt.unwrap_or_else(Vec::new))
+        .parse_stream(input)
+}
+
+pub fn input_object_type<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<InputObjectType, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("input").with(name()),
+        parser(directives),
+        parser(input_fields),
+    )
+        .map(|(position, name, directives, fields)| {
+            InputObjectType {
+                position, description: None, name, directives, fields,
             }
         })
         .parse_stream(input)
@@ -120,39 +318,288 @@ pub fn object_type<'a>(input: &mut TokenStream<'a>)
 
 pub fn type_definition<'a>(input: &mut TokenStream<'a>)
     -> ParseResult<TypeDefinition, TokenStream<'a>>
+{
+    choice((
+        parser(scalar_type).map(TypeDefinition::Scalar),
+        parser(object_type).map(TypeDefinition::Object),
+        parser(interface_type).map(TypeDefinition::Interface),
+        parser(union_type).map(TypeDefinition::Union),
+        parser(enum_type).map(TypeDefinition::Enum),
+        parser(input_object_type).map(TypeDefinition::InputObject),
+    ))
+        .parse_stream(input)
+}
+
+
+pub fn schema_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<SchemaExtension, TokenStream<'a>>
 {
     (
-        optional(parser(string)),
-        choice((
-            parser(scalar_type).map(TypeDefinition::Scalar),
-            parser(object_type).map(TypeDefinition::Object),
-        )),
-    )
-        // We can't set description inside type definition parser, because
-        // that means parser will need to backtrace, and that in turn
-        // means that error reporting is bad (along with performance)
-        .map(|(descr, mut def)| {
-            use schema::ast::TypeDefinition::*;
-            match def {
-                Scalar(ref mut s) => s.description = descr,
-                Object(ref mut o) => o.description = descr,
-                Interface(ref mut i) => i.description = descr,
-                Union(ref mut u) => u.description = descr,
-                Enum(ref mut e) => e.description = descr,
-                InputObject(ref mut o) => o.description = descr,
+        position().skip(ident("schema")),
+        parser(directives),
+        optional(
+            punct("{")
+            .with(many((
+                kind(T::Name).skip(punct(":")),
+                name(),
+            )))
+            .skip(punct("}"))
+        ),
+    )
+    .flat_map(|(position, directives, operations):
+               (_, _, Option<Vec<(Token, _)>>)|
+    {
+        let (query, mutation, subscription) =
+            schema_operations(position, operations.unwrap_or_else(Vec::new))?;
+        Ok(SchemaExtension {
+            position, directives, query, mutation, subscription,
+        })
+    })
+    .parse_stream(input)
+}
+
+pub fn scalar_type_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<ScalarTypeExtension, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("scalar").with(name()),
+        parser(directives),
+    )
+        .map(|(position, name, directives)| {
+            ScalarTypeExtension { position, name, directives }
+        })
+        .parse_stream(input)
+}
+
+pub fn object_type_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<ObjectTypeExtension, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("type").with(name()),
+        parser(implements_interfaces),
+        parser(directives),
+        parser(fields),
+    )
+        .map(|(position, name, interfaces, directives, fields)| {
+            ObjectTypeExtension {
+                position, name, directives,
+                implements_interfaces: interfaces,
+                fields,
             }
-            def
         })
         .parse_stream(input)
 }
 
+pub fn interface_type_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<InterfaceTypeExtension, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("interface").with(name()),
+        parser(implements_interfaces),
+        parser(directives),
+        parser(fields),
+    )
+        .map(|(position, name, interfaces, directives, fields)| {
+            InterfaceTypeExtension {
+                position, name, directives,
+                implements_interfaces: interfaces,
+                fields,
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn union_type_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<UnionTypeExtension, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("union").with(name()),
+        parser(directives),
+        optional(punct("=").with(parser(union_members))),
+    )
+        .map(|(position, name, directives, types)| {
+            UnionTypeExtension {
+                position, name, directives,
+                types: types.unwrap_or_else(Vec::new),
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn enum_type_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<EnumTypeExtension, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("enum").with(name()),
+        parser(directives),
+        optional(
+            punct("{")
+            .with(many1(parser(enum_value)))
+            .skip(punct("}"))
+        ),
+    )
+        .map(|(position, name, directives, values)| {
+            EnumTypeExtension {
+                position, name, directives,
+                values: values.unwrap_or_else(Vec::new),
+            }
+        })
+        .parse_stream(input)
+}
+
+pub fn input_object_type_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<InputObjectTypeExtension, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("input").with(name()),
+        parser(directives),
+        parser(input_fields),
+    )
+        .map(|(position, name, directives, fields)| {
+            InputObjectTypeExtension { position, name, directives, fields }
+        })
+        .parse_stream(input)
+}
+
+pub fn type_extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<TypeExtension, TokenStream<'a>>
+{
+    choice((
+        parser(scalar_type_extension).map(TypeExtension::Scalar),
+        parser(object_type_extension).map(TypeExtension::Object),
+        parser(interface_type_extension).map(TypeExtension::Interface),
+        parser(union_type_extension).map(TypeExtension::Union),
+        parser(enum_type_extension).map(TypeExtension::Enum),
+        parser(input_object_type_extension).map(TypeExtension::InputObject),
+    )).parse_stream(input)
+}
+
+pub fn extension<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<Definition, TokenStream<'a>>
+{
+    // All extensions share the leading `extend` keyword, so we consume it
+    // once here and dispatch on the keyword that follows.
+    ident("extend")
+        .with(choice((
+            parser(schema_extension).map(Definition::SchemaExtension),
+            parser(type_extension).map(Definition::TypeExtension),
+        )))
+        .parse_stream(input)
+}
+
+pub fn directive_definition<'a>(input: &mut TokenStream<'a>)
+    -> ParseResult<DirectiveDefinition, TokenStream<'a>>
+{
+    (
+        position(),
+        ident("directive").and(punct("@")).with(name()),
+        parser(arguments_definition),
+        optional(ident("repeatable")).map(|opt| opt.is_some()),
+        ident("on")
+            .with(optional(punct("|")))
+            .with(sep_by1(kind(T::Name), punct("|"))),
+    )
+    .flat_map(|(position, name, arguments, repeatable, locs):
+               (_, _, _, _, Vec<Token>)|
+    {
+        use schema::ast::DirectiveLocation::*;
+        let mut locations = Vec::with_capacity(locs.len());
+        let mut err = Errors::empty(position);
+        for tok in locs {
+            locations.push(match tok.value {
+                // executable locations
+                "QUERY" => Query,
+                "MUTATION" => Mutation,
+                "SUBSCRIPTION" => Subscription,
+                "FIELD" => Field,
+                "FRAGMENT_DEFINITION" => FragmentDefinition,
+                "FRAGMENT_SPREAD" => FragmentSpread,
+                "INLINE_FRAGMENT" => InlineFragment,
+                "VARIABLE_DEFINITION" => VariableDefinition,
+                // type-system locations
+                "SCHEMA" => Schema,
+                "SCALAR" => Scalar,
+                "OBJECT" => Object,
+                "FIELD_DEFINITION" => FieldDefinition,
+                "ARGUMENT_DEFINITION" => ArgumentDefinition,
+                "INTERFACE" => Interface,
+                "UNION" => Union,
+                "ENUM" => Enum,
+                "ENUM_VALUE" => EnumValue,
+                "INPUT_OBJECT" => InputObject,
+                "INPUT_FIELD_DEFINITION" => InputFieldDefinition,
+                _ => {
+                    err.add_error(Error::unexpected_token(tok));
+                    err.add_error(Error::expected_static_message(
+                        "one of the directive locations"));
+                    continue;
+                }
+            });
+        }
+        if !err.errors.is_empty() {
+            return Err(err);
+        }
+        Ok(DirectiveDefinition {
+            position, description: None, name, arguments, repeatable, locations,
+        })
+    })
+    .parse_stream(input)
+}
+
+/// Apply a leading description, parsed once by [`definition`], to whichever
+/// definition variant the rest of the choice produced.
+///
+/// None of `directive_definition`, `schema`, or `type_definition` parse
+/// their own leading description: doing so meant each had to consume it
+/// before discovering whether it was actually followed by its own keyword,
+/// which in turn forced `choice` to backtrack with `attempt` to recover.
+/// That backtracking also erased genuine errors raised *inside* the matched
+/// branch (e.g. a duplicate `query` operation, or an unrecognized directive
+/// location), since `attempt` can't tell "consumed input, then found a real
+/// problem" apart from "consumed input, then turned out to be the wrong
+/// branch". Parsing the description exactly once, before dispatching on the
+/// keyword, removes the need for `attempt` entirely.
+fn set_description(def: &mut Definition, description: Option<String>) {
+    match *def {
+        Definition::DirectiveDefinition(ref mut d) => d.description = description,
+        Definition::SchemaDefinition(ref mut s) => s.description = description,
+        Definition::TypeDefinition(ref mut t) => {
+            use schema::ast::TypeDefinition::*;
+            match *t {
+                Scalar(ref mut s) => s.description = description,
+                Object(ref mut o) => o.description = description,
+                Interface(ref mut i) => i.description = description,
+                Union(ref mut u) => u.description = description,
+                Enum(ref mut e) => e.description = description,
+                InputObject(ref mut o) => o.description = description,
+            }
+        }
+        _ => unreachable!("set_description called on a definition without one"),
+    }
+}
 
 pub fn definition<'a>(input: &mut TokenStream<'a>)
     -> ParseResult<Definition, TokenStream<'a>>
 {
     choice((
-        parser(schema).map(Definition::SchemaDefinition),
-        parser(type_definition).map(Definition::TypeDefinition),
+        parser(extension),
+        (
+            optional(parser(string)),
+            choice((
+                parser(directive_definition).map(Definition::DirectiveDefinition),
+                parser(schema).map(Definition::SchemaDefinition),
+                parser(type_definition).map(Definition::TypeDefinition),
+            )),
+        ).map(|(description, mut def)| {
+            set_description(&mut def, description);
+            def
+        }),
     )).parse_stream(input)
 }
 
@@ -163,7 +610,10 @@ pub fn parse_schema(s: &str) -> Result<Document, SchemaParseError> {
         .map(|d| Document { definitions: d })
         .skip(eof())
         .parse_stream(&mut tokens)
-        .map_err(|e| e.into_inner().error)?;
+        // The `schema` parser accumulates every problem it finds into a
+        // single `Errors` value; preserve all of them instead of keeping
+        // only the first.
+        .map_err(|e| SchemaParseError::from(e.into_inner().error))?;
 
     Ok(doc)
 }
@@ -186,6 +636,7 @@ mod test {
                 Definition::SchemaDefinition(
                     SchemaDefinition {
                         position: Pos { line: 1, column: 1 },
+                        description: None,
                         directives: vec![],
                         query: Some("Query".into()),
                         mutation: None,
@@ -195,4 +646,297 @@ mod test {
             ],
         });
     }
+
+    #[test]
+    fn described_type_definition() {
+        // The leading description is parsed once in `definition`, then
+        // applied to whichever keyword (`type` here) the choice dispatches
+        // to, rather than each branch guessing and backtracking.
+        let doc = ast("\"\"\"Doc\"\"\" type Foo { bar: String! }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeDefinition(TypeDefinition::Object(o))] => {
+                assert_eq!(o.description, Some("Doc".into()));
+                assert_eq!(o.name, "Foo");
+                // Fields must actually round-trip rather than being dropped.
+                match o.fields.as_slice() {
+                    [field] => {
+                        assert_eq!(field.name, "bar");
+                        assert_eq!(
+                            field.field_type,
+                            Type::NonNullType(Box::new(
+                                Type::NamedType("String".into())
+                            ))
+                        );
+                    }
+                    _ => panic!("unexpected fields: {:?}", o.fields),
+                }
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn described_schema_definition() {
+        // Same as `described_type_definition`, but dispatching to `schema`.
+        let doc = ast("\"\"\"Root schema\"\"\" schema { query: Query }");
+        match doc.definitions.as_slice() {
+            [Definition::SchemaDefinition(s)] => {
+                assert_eq!(s.description, Some("Root schema".into()));
+                assert_eq!(s.query, Some("Query".into()));
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn described_directive_definition() {
+        // Same as `described_type_definition`, but dispatching to
+        // `directive_definition`.
+        let doc = ast(
+            "\"\"\"Doc\"\"\" directive @foo on FIELD"
+        );
+        match doc.definitions.as_slice() {
+            [Definition::DirectiveDefinition(d)] => {
+                assert_eq!(d.description, Some("Doc".into()));
+                assert_eq!(d.name, "foo");
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn duplicate_schema_operation_reports_errors_at_definition_position() {
+        // `schema`'s `flat_map` error must reach the caller as-is, rather
+        // than being replaced by a misleading "expected scalar/type/
+        // interface/..." error from another branch of `definition`.
+        let err = parse_schema("schema { query: Query query: Query }")
+            .unwrap_err();
+        assert_eq!(err.position(), Pos { line: 1, column: 1 });
+        assert_eq!(err.errors().len(), 1);
+        assert!(
+            err.errors()[0].message.contains("duplicate `query` operation"),
+            "unexpected error message: {:?}", err.errors()[0].message
+        );
+    }
+
+    #[test]
+    fn bad_directive_location_reports_errors_at_definition_position() {
+        // `directive_definition`'s `flat_map` errors must reach the caller
+        // as-is, rather than being replaced by errors from unrelated branches.
+        let err = parse_schema("directive @foo on BOGUS")
+            .unwrap_err();
+        assert_eq!(err.position(), Pos { line: 1, column: 1 });
+        assert_eq!(err.errors().len(), 2);
+        assert!(
+            err.errors().iter()
+                .any(|e| e.message.contains("one of the directive locations")),
+            "unexpected errors: {:?}", err.errors()
+        );
+    }
+
+    #[test]
+    fn interface_type_definition() {
+        let doc = ast("interface Node { id: ID! }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeDefinition(TypeDefinition::Interface(i))] => {
+                assert_eq!(i.name, "Node");
+                match i.fields.as_slice() {
+                    [field] => assert_eq!(field.name, "id"),
+                    _ => panic!("unexpected fields: {:?}", i.fields),
+                }
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn union_type_definition() {
+        // `union_members` accepts an optional leading `|` on top of the
+        // `sep_by1`, so both forms must produce the same member list.
+        let without_leading_pipe = ast("union U = A | B");
+        let with_leading_pipe = ast("union U = | A | B");
+        for doc in &[without_leading_pipe, with_leading_pipe] {
+            match doc.definitions.as_slice() {
+                [Definition::TypeDefinition(TypeDefinition::Union(u))] => {
+                    assert_eq!(u.name, "U");
+                    assert_eq!(u.types, vec!["A".to_string(), "B".to_string()]);
+                }
+                _ => panic!("unexpected definitions: {:?}", doc.definitions),
+            }
+        }
+    }
+
+    #[test]
+    fn union_type_definition_without_members() {
+        let doc = ast("union U");
+        match doc.definitions.as_slice() {
+            [Definition::TypeDefinition(TypeDefinition::Union(u))] => {
+                assert_eq!(u.name, "U");
+                assert_eq!(u.types, Vec::<String>::new());
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn enum_type_definition() {
+        let doc = ast("enum Color { RED GREEN BLUE }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeDefinition(TypeDefinition::Enum(e))] => {
+                assert_eq!(e.name, "Color");
+                let names: Vec<_> =
+                    e.values.iter().map(|v| v.name.clone()).collect();
+                assert_eq!(names, vec!["RED", "GREEN", "BLUE"]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn enum_type_definition_without_values() {
+        let doc = ast("enum Color");
+        match doc.definitions.as_slice() {
+            [Definition::TypeDefinition(TypeDefinition::Enum(e))] => {
+                assert_eq!(e.name, "Color");
+                assert_eq!(e.values, vec![]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn input_object_type_definition() {
+        let doc = ast("input Point { x: Int y: Int }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeDefinition(TypeDefinition::InputObject(o))] => {
+                assert_eq!(o.name, "Point");
+                let names: Vec<_> =
+                    o.fields.iter().map(|f| f.name.clone()).collect();
+                assert_eq!(names, vec!["x", "y"]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn input_object_type_definition_without_fields() {
+        let doc = ast("input Point");
+        match doc.definitions.as_slice() {
+            [Definition::TypeDefinition(TypeDefinition::InputObject(o))] => {
+                assert_eq!(o.name, "Point");
+                assert_eq!(o.fields, vec![]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn schema_extension() {
+        let doc = ast("extend schema { mutation: Mutation }");
+        match doc.definitions.as_slice() {
+            [Definition::SchemaExtension(s)] => {
+                assert_eq!(s.mutation, Some("Mutation".into()));
+                assert_eq!(s.query, None);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn scalar_type_extension() {
+        let doc = ast("extend scalar Date @format");
+        match doc.definitions.as_slice() {
+            [Definition::TypeExtension(TypeExtension::Scalar(s))] => {
+                assert_eq!(s.name, "Date");
+                assert_eq!(s.directives.len(), 1);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn object_type_extension() {
+        let doc = ast("extend type Foo { bar: Int }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeExtension(TypeExtension::Object(o))] => {
+                assert_eq!(o.name, "Foo");
+                match o.fields.as_slice() {
+                    [field] => assert_eq!(field.name, "bar"),
+                    _ => panic!("unexpected fields: {:?}", o.fields),
+                }
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn object_type_extension_with_nothing_after_the_name() {
+        // `implements`, directives, and the field block are all optional, so
+        // an extension can legitimately add nothing but itself to `choice`'s
+        // dispatch -- e.g. while incrementally building up a schema.
+        let doc = ast("extend type Foo");
+        match doc.definitions.as_slice() {
+            [Definition::TypeExtension(TypeExtension::Object(o))] => {
+                assert_eq!(o.name, "Foo");
+                assert_eq!(o.implements_interfaces, vec![]);
+                assert_eq!(o.fields, vec![]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn interface_type_extension() {
+        let doc = ast("extend interface Node { id: ID! }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeExtension(TypeExtension::Interface(i))] => {
+                assert_eq!(i.name, "Node");
+                match i.fields.as_slice() {
+                    [field] => assert_eq!(field.name, "id"),
+                    _ => panic!("unexpected fields: {:?}", i.fields),
+                }
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn union_type_extension() {
+        let doc = ast("extend union U = A | B");
+        match doc.definitions.as_slice() {
+            [Definition::TypeExtension(TypeExtension::Union(u))] => {
+                assert_eq!(u.name, "U");
+                assert_eq!(u.types, vec!["A".to_string(), "B".to_string()]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn enum_type_extension() {
+        let doc = ast("extend enum Color { RED GREEN }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeExtension(TypeExtension::Enum(e))] => {
+                assert_eq!(e.name, "Color");
+                let names: Vec<_> =
+                    e.values.iter().map(|v| v.name.clone()).collect();
+                assert_eq!(names, vec!["RED", "GREEN"]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
+
+    #[test]
+    fn input_object_type_extension() {
+        let doc = ast("extend input Point { x: Int y: Int }");
+        match doc.definitions.as_slice() {
+            [Definition::TypeExtension(TypeExtension::InputObject(o))] => {
+                assert_eq!(o.name, "Point");
+                let names: Vec<_> =
+                    o.fields.iter().map(|f| f.name.clone()).collect();
+                assert_eq!(names, vec!["x", "y"]);
+            }
+            _ => panic!("unexpected definitions: {:?}", doc.definitions),
+        }
+    }
 }
\ No newline at end of file